@@ -58,6 +58,8 @@ pub mod blocking;
 #[cfg(feature = "async")]
 pub mod async_await;
 
+pub mod spi_device;
+
 /// The bits that represent the thermocouple value when reading the first u16 from the sensor
 const THERMOCOUPLE_BITS: RangeInclusive<usize> = 2..=15;
 /// The bit that indicates some kind of fault when reading the first u16 from the sensor
@@ -106,6 +108,41 @@ impl Unit {
             Unit::Kelvin => celsius + 273.15,
         }
     }
+
+    /// Converts a value already in this unit back into degrees celsius
+    pub fn to_celsius(&self, value: f32) -> f32 {
+        match self {
+            Unit::Celsius => value,
+            Unit::Fahrenheit => (value - 32.) / 1.8,
+            Unit::Kelvin => value - 273.15,
+        }
+    }
+}
+
+/// A thermocouple type whose ITS-90 linearization tables this crate ships.
+///
+/// The MAX31855 is hardwired for Type-K, but the chip's linear conversion can be
+/// undone and re-applied with a different type's polynomial, turning the part into
+/// a general-purpose thermocouple front-end in software. This mirrors the TCTYPE
+/// selection the MAX31856 exposes in hardware.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThermocoupleType {
+    /// Type B (platinum-rhodium)
+    B,
+    /// Type E (nickel-chromium / constantan)
+    E,
+    /// Type J (iron / constantan)
+    J,
+    /// Type K (nickel-chromium / nickel-alumel), the MAX31855's native type
+    K,
+    /// Type N (nicrosil / nisil)
+    N,
+    /// Type R (platinum-rhodium)
+    R,
+    /// Type S (platinum-rhodium)
+    S,
+    /// Type T (copper / constantan)
+    T,
 }
 
 /// Possible MAX31855 readings
@@ -157,6 +194,40 @@ impl FullResultRaw {
             unit,
         }
     }
+
+    /// Apply a NIST ITS-90 linearization to the hot-junction temperature.
+    ///
+    /// The MAX31855 computes the thermocouple temperature assuming a fixed linear
+    /// Type-K Seebeck coefficient, which diverges from the true Type-K response once
+    /// the reference junction drifts away from 0 °C. This undoes the chip's linear
+    /// cold-junction compensation and re-applies the NIST Type-K polynomials to
+    /// recover a corrected hot-junction temperature, returned in the provided `Unit`.
+    #[cfg(feature = "libm")]
+    pub fn linearize(&self, unit: Unit) -> f32 {
+        let tr = Reading::Thermocouple.convert(self.thermocouple);
+        let tint = Reading::Internal.convert(self.internal);
+        unit.convert(nist::correct_type_k(tr, tint))
+    }
+
+    /// Re-interpret the reading as though a thermocouple of `kind` were attached.
+    ///
+    /// The chip's Type-K linear conversion is undone to recover the measured emf, the
+    /// Type-K cold-junction compensation voltage the chip physically applied is added
+    /// back, and the resulting total voltage is converted to temperature with the
+    /// selected type's NIST inverse polynomial. The result is returned in `unit`.
+    ///
+    /// Note that the cold-junction compensation always uses the chip's Type-K
+    /// assumption (the only response it can physically apply), *not* the selected
+    /// type's. For any non-K type this introduces a systematic error that grows with
+    /// the internal temperature's distance from 0 °C, so this is an approximation
+    /// rather than a true per-type linearization — keep the reference junction near
+    /// 0 °C for best accuracy.
+    #[cfg(feature = "libm")]
+    pub fn read_thermocouple_as(&self, kind: ThermocoupleType, unit: Unit) -> f32 {
+        let tr = Reading::Thermocouple.convert(self.thermocouple);
+        let tint = Reading::Internal.convert(self.internal);
+        unit.convert(nist::convert_as(kind, tr, tint))
+    }
 }
 
 /// Represents the data contained in a full 32-bit read from the MAX31855 as degrees in the included Unit
@@ -170,6 +241,626 @@ pub struct FullResult {
     pub unit: Unit,
 }
 
+impl FullResult {
+    /// Apply a NIST ITS-90 linearization to the hot-junction temperature.
+    ///
+    /// See [`FullResultRaw::linearize`] for details; this operates on already-converted
+    /// readings by first folding them back to degrees celsius via `self.unit`.
+    #[cfg(feature = "libm")]
+    pub fn linearize(&self, unit: Unit) -> f32 {
+        let tr = self.unit.to_celsius(self.thermocouple);
+        let tint = self.unit.to_celsius(self.internal);
+        unit.convert(nist::correct_type_k(tr, tint))
+    }
+}
+
+/// NIST ITS-90 Type-K thermocouple polynomials used to linearize the chip's
+/// fixed-coefficient cold-junction compensation.
+///
+/// The MAX31855 internally treats the Type-K response as the constant
+/// 41.276 µV/°C slope; these tables restore the true non-linear relationship.
+/// Evaluation is done in `f64` (the polynomials span ten-plus orders of
+/// magnitude in their coefficients) and the result is narrowed back to `f32`.
+#[cfg(feature = "libm")]
+mod nist {
+    /// The chip's assumed Type-K sensitivity, in mV/°C.
+    const SEEBECK_MV_PER_C: f64 = 0.041276;
+
+    /// Forward coefficients for 0 °C and above, plus the exponential correction.
+    const FWD_POS: [f64; 10] = [
+        -0.176_004_136_860_e-1,
+        0.389_212_049_750_e-1,
+        0.185_587_700_320_e-4,
+        -0.994_575_928_740_e-7,
+        0.318_409_457_190_e-9,
+        -0.560_728_448_890_e-12,
+        0.560_750_590_590_e-15,
+        -0.320_207_200_030_e-18,
+        0.971_511_471_520_e-22,
+        -0.121_047_212_750_e-25,
+    ];
+    const FWD_POS_EXP: [f64; 3] = [
+        0.118_597_600_000_e0,
+        -0.118_343_200_000_e-3,
+        0.126_968_600_000_e3,
+    ];
+
+    /// Forward coefficients below 0 °C (no exponential term).
+    const FWD_NEG: [f64; 11] = [
+        0.0,
+        0.394_501_280_250_e-1,
+        0.236_223_735_980_e-4,
+        -0.328_589_067_840_e-6,
+        -0.499_048_287_770_e-8,
+        -0.675_090_591_730_e-10,
+        -0.574_103_274_280_e-12,
+        -0.310_888_728_940_e-14,
+        -0.104_516_093_650_e-16,
+        -0.198_892_668_780_e-19,
+        -0.163_226_974_860_e-22,
+    ];
+
+    /// Inverse coefficients for -5.891..0 mV.
+    const INV_LO: [f64; 9] = [
+        0.0,
+        2.517_346_2_e1,
+        -1.166_287_8_e0,
+        -1.083_363_8_e0,
+        -8.977_354_0_e-1,
+        -3.734_237_7_e-1,
+        -8.663_264_3_e-2,
+        -1.045_059_8_e-2,
+        -5.192_057_7_e-4,
+    ];
+    /// Inverse coefficients for 0..20.644 mV.
+    const INV_MID: [f64; 10] = [
+        0.0,
+        2.508_355_0_e1,
+        7.860_106_0_e-2,
+        -2.503_131_0_e-1,
+        8.315_270_0_e-2,
+        -1.228_034_0_e-2,
+        9.804_036_0_e-4,
+        -4.413_030_0_e-5,
+        1.057_734_0_e-6,
+        -1.052_755_0_e-8,
+    ];
+    /// Inverse coefficients for 20.644..54.886 mV.
+    const INV_HI: [f64; 7] = [
+        -1.318_058_0_e2,
+        4.830_222_0_e1,
+        -1.646_031_0_e0,
+        5.464_731_0_e-2,
+        -9.650_715_0_e-4,
+        8.802_193_0_e-6,
+        -3.110_810_0_e-8,
+    ];
+
+    /// Evaluate a polynomial with Horner's method.
+    fn horner(coeffs: &[f64], x: f64) -> f64 {
+        coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c)
+    }
+
+    /// Type-K forward polynomial: temperature in °C to thermoelectric voltage in mV.
+    fn forward(t: f64) -> f64 {
+        if t < 0.0 {
+            horner(&FWD_NEG, t)
+        } else {
+            let d = t - FWD_POS_EXP[2];
+            horner(&FWD_POS, t) + FWD_POS_EXP[0] * libm::exp(FWD_POS_EXP[1] * d * d)
+        }
+    }
+
+    /// Type-K inverse polynomial: thermoelectric voltage in mV to temperature in °C.
+    fn inverse(mv: f64) -> f64 {
+        let coeffs: &[f64] = if mv < 0.0 { &INV_LO } else if mv <= 20.644 { &INV_MID } else { &INV_HI };
+        horner(coeffs, mv)
+    }
+
+    /// Recover the corrected hot-junction temperature (°C) from the chip's reported
+    /// thermocouple (`tr`) and internal (`tint`) temperatures, both in °C.
+    pub(crate) fn correct_type_k(tr: f32, tint: f32) -> f32 {
+        convert_as(super::ThermocoupleType::K, tr, tint)
+    }
+
+    /// Re-interpret a Type-K reading as another thermocouple type.
+    ///
+    /// The cold-junction compensation voltage is always the Type-K value the chip
+    /// physically applied; only the final inverse polynomial varies with `kind`.
+    pub(crate) fn convert_as(kind: super::ThermocoupleType, tr: f32, tint: f32) -> f32 {
+        let tr = tr as f64;
+        let tint = tint as f64;
+        let vout = SEEBECK_MV_PER_C * (tr - tint);
+        let vcj = forward(tint);
+        inverse_for(kind, vout + vcj) as f32
+    }
+
+    /// Convert a total thermoelectric voltage (mV) to temperature (°C) using the
+    /// selected type's NIST inverse polynomial.
+    fn inverse_for(kind: super::ThermocoupleType, mv: f64) -> f64 {
+        use super::ThermocoupleType::*;
+        let ranges: &[(f64, &[f64])] = match kind {
+            B => &tables::INV_B,
+            E => &tables::INV_E,
+            J => &tables::INV_J,
+            K => return inverse(mv),
+            N => &tables::INV_N,
+            R => &tables::INV_R,
+            S => &tables::INV_S,
+            T => &tables::INV_T,
+        };
+
+        // Pick the first range whose upper bound covers the voltage, else the last.
+        let coeffs = ranges
+            .iter()
+            .find(|(upper, _)| mv <= *upper)
+            .map(|(_, c)| *c)
+            .unwrap_or(ranges[ranges.len() - 1].1);
+        horner(coeffs, mv)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Horner tables are only as trustworthy as the transcription; pin a few
+        /// published NIST ITS-90 reference points so a transposed digit fails loudly.
+        fn close(a: f64, b: f64, tol: f64) -> bool {
+            (a - b).abs() <= tol
+        }
+
+        #[test]
+        fn type_k_forward_reference_points() {
+            // NIST ITS-90 Type-K: 0 °C -> 0 mV, 500 °C -> 20.644 mV, 1000 °C -> 41.276 mV
+            assert!(close(forward(0.0), 0.0, 0.01));
+            assert!(close(forward(500.0), 20.644, 0.02));
+            assert!(close(forward(1000.0), 41.276, 0.02));
+        }
+
+        #[test]
+        fn type_k_round_trips() {
+            assert!(close(inverse(forward(1000.0)), 1000.0, 0.5));
+            assert!(close(inverse(forward(250.0)), 250.0, 0.5));
+            assert!(close(inverse(forward(-100.0)), -100.0, 0.5));
+        }
+
+        #[test]
+        fn non_k_inverse_reference_points() {
+            use super::super::ThermocoupleType::*;
+            // Published ITS-90 emf values for each type at a representative temperature.
+            assert!(close(inverse_for(J, 16.327), 300.0, 0.5));
+            assert!(close(inverse_for(T, 9.288), 200.0, 0.5));
+            assert!(close(inverse_for(E, 21.036), 300.0, 0.5));
+            // The 0 mV anchor must land on 0 °C for the types whose range starts there.
+            assert!(close(inverse_for(J, 0.0), 0.0, 0.1));
+            assert!(close(inverse_for(E, 0.0), 0.0, 0.1));
+            assert!(close(inverse_for(T, 0.0), 0.0, 0.1));
+        }
+    }
+
+    /// NIST ITS-90 inverse coefficient tables for the non-K thermocouple types.
+    ///
+    /// Each entry is `(upper voltage bound in mV, coefficients)`; ranges are ordered
+    /// low-to-high and the last one is used for voltages above every listed bound.
+    mod tables {
+        pub(super) const INV_B: [(f64, &[f64]); 2] = [
+            (
+                2.431,
+                &[
+                    9.842_332_1_e1,
+                    6.997_150_0_e2,
+                    -8.476_530_4_e2,
+                    1.005_264_4_e3,
+                    -8.334_595_2_e2,
+                    4.550_854_2_e2,
+                    -1.552_303_7_e2,
+                    2.988_675_0_e1,
+                    -2.474_286_0_e0,
+                ],
+            ),
+            (
+                13.820,
+                &[
+                    2.131_507_1_e2,
+                    2.851_050_4_e2,
+                    -5.274_288_7_e1,
+                    9.916_080_4_e0,
+                    -1.296_530_3_e0,
+                    1.119_587_0_e-1,
+                    -6.062_519_9_e-3,
+                    1.866_169_6_e-4,
+                    -2.487_858_5_e-6,
+                ],
+            ),
+        ];
+
+        pub(super) const INV_E: [(f64, &[f64]); 2] = [
+            (
+                0.0,
+                &[
+                    0.0,
+                    1.697_728_8_e1,
+                    -4.351_497_0_e-1,
+                    -1.585_969_7_e-1,
+                    -9.250_287_1_e-2,
+                    -2.608_431_4_e-2,
+                    -4.136_019_9_e-3,
+                    -3.403_403_0_e-4,
+                    -1.156_489_0_e-5,
+                ],
+            ),
+            (
+                76.373,
+                &[
+                    0.0,
+                    1.705_703_5_e1,
+                    -2.330_175_9_e-1,
+                    6.543_558_5_e-3,
+                    -7.356_274_9_e-5,
+                    -1.789_600_1_e-6,
+                    8.403_616_5_e-8,
+                    -1.373_587_9_e-9,
+                    1.062_982_3_e-11,
+                    -3.244_708_7_e-14,
+                ],
+            ),
+        ];
+
+        pub(super) const INV_J: [(f64, &[f64]); 3] = [
+            (
+                0.0,
+                &[
+                    0.0,
+                    1.952_826_8_e1,
+                    -1.228_618_5_e0,
+                    -1.075_217_8_e0,
+                    -5.908_693_3_e-1,
+                    -1.725_671_3_e-1,
+                    -2.813_151_3_e-2,
+                    -2.396_337_0_e-3,
+                    -8.382_332_1_e-5,
+                ],
+            ),
+            (
+                42.919,
+                &[
+                    0.0,
+                    1.978_425_0_e1,
+                    -2.001_204_0_e-1,
+                    1.036_969_0_e-2,
+                    -2.549_687_0_e-4,
+                    3.585_153_0_e-6,
+                    -5.344_285_0_e-8,
+                    5.099_890_0_e-10,
+                ],
+            ),
+            (
+                69.553,
+                &[
+                    -3.113_581_87_e3,
+                    3.005_436_84_e2,
+                    -9.947_732_30_e0,
+                    1.702_766_30_e-1,
+                    -1.430_334_68_e-3,
+                    4.738_860_84_e-6,
+                ],
+            ),
+        ];
+
+        pub(super) const INV_N: [(f64, &[f64]); 3] = [
+            (
+                0.0,
+                &[
+                    0.0,
+                    3.843_684_7_e1,
+                    1.101_048_5_e0,
+                    5.222_931_2_e0,
+                    7.206_052_5_e0,
+                    5.848_858_6_e0,
+                    2.775_491_6_e0,
+                    7.707_516_6_e-1,
+                    1.158_266_5_e-1,
+                    7.313_886_8_e-3,
+                ],
+            ),
+            (
+                20.613,
+                &[
+                    0.0,
+                    3.868_960_0_e1,
+                    -1.082_670_0_e0,
+                    4.702_050_0_e-2,
+                    -2.121_690_0_e-6,
+                    -1.172_720_0_e-4,
+                    5.392_800_0_e-6,
+                    -7.981_560_0_e-8,
+                ],
+            ),
+            (
+                47.513,
+                &[
+                    1.972_485_0_e1,
+                    3.300_943_0_e1,
+                    -3.915_159_0_e-1,
+                    9.855_391_0_e-3,
+                    -1.274_371_0_e-4,
+                    7.767_022_0_e-7,
+                ],
+            ),
+        ];
+
+        pub(super) const INV_R: [(f64, &[f64]); 4] = [
+            (
+                1.923,
+                &[
+                    0.0,
+                    1.889_138_0_e2,
+                    -9.383_529_0_e1,
+                    1.306_861_9_e2,
+                    -2.270_358_0_e2,
+                    3.514_565_9_e2,
+                    -3.895_390_0_e2,
+                    2.823_947_1_e2,
+                    -1.260_728_1_e2,
+                    3.135_361_1_e1,
+                    -3.318_776_9_e0,
+                ],
+            ),
+            (
+                11.361,
+                &[
+                    1.334_584_505_e1,
+                    1.472_644_573_e2,
+                    -1.844_024_844_e1,
+                    4.031_129_726_e0,
+                    -6.249_428_360_e-1,
+                    6.468_412_046_e-2,
+                    -4.458_750_426_e-3,
+                    1.994_710_149_e-4,
+                    -5.313_401_790_e-6,
+                    6.481_976_217_e-8,
+                ],
+            ),
+            (
+                19.739,
+                &[
+                    -8.199_599_416_e1,
+                    1.553_962_042_e2,
+                    -8.342_197_663_e0,
+                    4.279_433_549_e-1,
+                    -1.191_577_910_e-2,
+                    1.492_290_091_e-4,
+                ],
+            ),
+            (
+                21.103,
+                &[
+                    3.406_177_836_e4,
+                    -7.023_729_171_e3,
+                    5.582_903_813_e2,
+                    -1.952_394_635_e1,
+                    2.560_740_231_e-1,
+                ],
+            ),
+        ];
+
+        pub(super) const INV_S: [(f64, &[f64]); 4] = [
+            (
+                1.874,
+                &[
+                    0.0,
+                    1.849_494_60_e2,
+                    -8.005_040_62_e1,
+                    1.022_374_30_e2,
+                    -1.522_485_92_e2,
+                    1.888_213_43_e2,
+                    -1.590_859_41_e2,
+                    8.230_278_80_e1,
+                    -2.341_819_44_e1,
+                    2.797_862_60_e0,
+                ],
+            ),
+            (
+                11.950,
+                &[
+                    1.291_507_177_e1,
+                    1.466_298_863_e2,
+                    -1.534_713_402_e1,
+                    3.145_945_973_e0,
+                    -4.163_257_839_e-1,
+                    3.187_963_771_e-2,
+                    -1.291_637_500_e-3,
+                    2.183_475_087_e-5,
+                    -1.447_379_511_e-7,
+                    8.211_272_125_e-9,
+                ],
+            ),
+            (
+                17.536,
+                &[
+                    -8.087_801_117_e1,
+                    1.621_573_104_e2,
+                    -8.536_869_453_e0,
+                    4.719_686_976_e-1,
+                    -1.441_693_666_e-2,
+                    2.081_618_890_e-4,
+                ],
+            ),
+            (
+                18.693,
+                &[
+                    5.333_875_126_e4,
+                    -1.235_892_298_e4,
+                    1.092_657_613_e3,
+                    -4.265_693_686_e1,
+                    6.247_205_420_e-1,
+                ],
+            ),
+        ];
+
+        pub(super) const INV_T: [(f64, &[f64]); 2] = [
+            (
+                0.0,
+                &[
+                    0.0,
+                    2.594_919_2_e1,
+                    -2.131_696_7_e-1,
+                    7.901_869_2_e-1,
+                    4.252_777_7_e-1,
+                    1.330_447_3_e-1,
+                    2.024_144_6_e-2,
+                    1.266_817_1_e-3,
+                ],
+            ),
+            (
+                20.872,
+                &[
+                    0.0,
+                    2.592_800_0_e1,
+                    -7.602_961_0_e-1,
+                    4.637_791_0_e-2,
+                    -2.165_394_0_e-3,
+                    6.048_144_0_e-5,
+                    -7.293_422_0_e-7,
+                ],
+            ),
+        ];
+    }
+}
+
+/// The fault bits reported by the MAX31855, kept as a bitfield instead of being
+/// collapsed into a single [`Error`] variant.
+///
+/// Unlike [`Error`], this never discards the accompanying temperature readings, so
+/// callers can log or diagnose a fault while still seeing the (possibly degraded)
+/// sample and apply their own retry or averaging policy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FaultStatus(u8);
+
+impl FaultStatus {
+    /// The summary fault bit (16) was set in the response from the MAX31855
+    pub const FAULT: u8 = 1 << 0;
+    /// The SCV fault bit (2) was set in the response from the MAX31855
+    pub const VCC_SHORT: u8 = 1 << 1;
+    /// The SCG fault bit (1) was set in the response from the MAX31855
+    pub const GROUND_SHORT: u8 = 1 << 2;
+    /// The OC fault bit (0) was set in the response from the MAX31855
+    pub const OPEN_CIRCUIT: u8 = 1 << 3;
+
+    /// `true` if the summary fault bit is set
+    pub fn fault(&self) -> bool {
+        self.0 & Self::FAULT != 0
+    }
+
+    /// `true` if the thermocouple is shorted to VCC
+    pub fn vcc_short(&self) -> bool {
+        self.0 & Self::VCC_SHORT != 0
+    }
+
+    /// `true` if the thermocouple is shorted to ground
+    pub fn ground_short(&self) -> bool {
+        self.0 & Self::GROUND_SHORT != 0
+    }
+
+    /// `true` if the thermocouple is open (missing)
+    pub fn open_circuit(&self) -> bool {
+        self.0 & Self::OPEN_CIRCUIT != 0
+    }
+}
+
+/// A full 32-bit read paired with its [`FaultStatus`], as raw ADC counts.
+///
+/// The temperatures are always populated; inspect `faults` to decide whether to
+/// trust them.
+#[derive(Debug)]
+pub struct FullResultRawWithFaults {
+    /// The temperature of the thermocouple as raw ADC counts
+    pub thermocouple: i16,
+    /// The temperature of the MAX31855 reference junction as raw ADC counts
+    pub internal: i16,
+    /// The fault bits reported alongside the readings
+    pub faults: FaultStatus,
+}
+
+impl FullResultRawWithFaults {
+    /// Convert the raw ADC counts into degrees in the provided Unit, preserving the faults
+    pub fn convert(self, unit: Unit) -> FullResultWithFaults {
+        let thermocouple = unit.convert(Reading::Thermocouple.convert(self.thermocouple));
+        let internal = unit.convert(Reading::Internal.convert(self.internal));
+
+        FullResultWithFaults {
+            thermocouple,
+            internal,
+            unit,
+            faults: self.faults,
+        }
+    }
+}
+
+/// A full 32-bit read paired with its [`FaultStatus`], as degrees in the included Unit.
+#[derive(Debug)]
+pub struct FullResultWithFaults {
+    /// The temperature of the thermocouple
+    pub thermocouple: f32,
+    /// The temperature of the MAX31855 reference junction
+    pub internal: f32,
+    /// The unit that the temperatures are in
+    pub unit: Unit,
+    /// The fault bits reported alongside the readings
+    pub faults: FaultStatus,
+}
+
+/// Accumulates several raw readings and averages their ADC counts before any unit
+/// conversion, giving stable output from the MAX31855's noisy, ~100 ms conversions.
+///
+/// The averaging math lives here; the I/O layer is responsible for spacing reads by
+/// a caller-supplied minimum interval so a stale conversion is never sampled twice.
+/// Any fault bits seen mid-burst are OR-ed together and surfaced on the result's
+/// [`FaultStatus`] rather than being silently folded into the mean.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Averager {
+    thermocouple: i32,
+    internal: i32,
+    faults: u8,
+    count: u16,
+}
+
+impl Averager {
+    /// Create an empty averager
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one raw reading (with its faults) into the running total
+    pub fn push(&mut self, reading: FullResultRawWithFaults) {
+        self.thermocouple += reading.thermocouple as i32;
+        self.internal += reading.internal as i32;
+        self.faults |= reading.faults.0;
+        self.count += 1;
+    }
+
+    /// The number of samples accumulated so far
+    pub fn count(&self) -> u16 {
+        self.count
+    }
+
+    /// Produce the mean raw reading, or `None` if no samples were accumulated.
+    ///
+    /// The returned [`FaultStatus`] is the union of the faults seen across the burst.
+    pub fn finish(self) -> Option<FullResultRawWithFaults> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let count = self.count as i32;
+        Some(FullResultRawWithFaults {
+            thermocouple: (self.thermocouple / count) as i16,
+            internal: (self.internal / count) as i16,
+            faults: FaultStatus(self.faults),
+        })
+    }
+}
+
 /// A helper module to abstract over the non-I/O portions of the driver
 ///
 /// This allows for maximal shared code between async and blocking impl
@@ -259,4 +950,41 @@ mod io_less {
     pub(crate) fn read_all(full_result: FullResultRaw, unit: Unit) -> FullResult {
         full_result.convert(unit)
     }
+
+    /// Reads both the thermocouple and the internal temperatures, leaving them as raw ADC counts, and reports the fault bits instead of aborting on the first one set
+    pub(crate) fn read_all_with_faults_raw(buffer: [u8; 4]) -> FullResultRawWithFaults {
+        let first_u16 = (buffer[0] as u16) << 8 | (buffer[1] as u16);
+        let second_u16 = (buffer[2] as u16) << 8 | (buffer[3] as u16);
+
+        let mut bits = 0u8;
+        if first_u16.get_bit(FAULT_BIT) {
+            bits |= FaultStatus::FAULT;
+        }
+        if second_u16.get_bit(FAULT_VCC_SHORT_BIT) {
+            bits |= FaultStatus::VCC_SHORT;
+        }
+        if second_u16.get_bit(FAULT_GROUND_SHORT_BIT) {
+            bits |= FaultStatus::GROUND_SHORT;
+        }
+        if second_u16.get_bit(FAULT_NO_THERMOCOUPLE_BIT) {
+            bits |= FaultStatus::OPEN_CIRCUIT;
+        }
+
+        let thermocouple = bits_to_i16(first_u16.get_bits(THERMOCOUPLE_BITS), 14, 4, 2);
+        let internal = bits_to_i16(second_u16.get_bits(INTERNAL_BITS), 12, 16, 4);
+
+        FullResultRawWithFaults {
+            thermocouple,
+            internal,
+            faults: FaultStatus(bits),
+        }
+    }
+
+    /// Reads both the thermocouple and the internal temperatures, converts them into degrees in the provided unit and reports the fault bits instead of aborting
+    pub(crate) fn read_all_with_faults(
+        full_result: FullResultRawWithFaults,
+        unit: Unit,
+    ) -> FullResultWithFaults {
+        full_result.convert(unit)
+    }
 }
@@ -0,0 +1,198 @@
+//! A driver built on the `embedded-hal` 1.0 [`SpiDevice`] bus-sharing model.
+//!
+//! Unlike [`blocking`](crate::blocking) and [`async_await`](crate::async_await), which
+//! thread an explicit chip-select pin through every call, the types here own a
+//! [`SpiDevice`] and let the bus manage CS. This aligns the crate with the HAL 1.0
+//! model and lets several MAX31855s plus other sensors share one bus via
+//! `embedded-hal-bus`.
+//!
+//! [`SpiDevice`]: embedded_hal::spi::SpiDevice
+
+use crate::io_less::*;
+use crate::{Averager, Error, FullResult, FullResultRaw, FullResultWithFaults, Unit};
+use core::num::NonZeroU16;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::spi::SpiDevice;
+
+/// A MAX31855 driver that owns an [`embedded_hal::spi::SpiDevice`].
+///
+/// Construct it once from a `SpiDevice` and call the read methods without passing a
+/// chip-select pin; the bus sequences CS for you.
+pub struct Max31855<SPI> {
+    spi: SPI,
+}
+
+impl<SPI> Max31855<SPI>
+where
+    SPI: SpiDevice,
+{
+    /// Create a driver that owns `spi`
+    pub fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+
+    /// Release the underlying [`SpiDevice`]
+    pub fn release(self) -> SPI {
+        self.spi
+    }
+
+    /// Reads the thermocouple temperature and leaves it as a raw ADC count. Checks if there is a fault but doesn't detect what kind of fault it is
+    pub fn read_thermocouple_raw(&mut self) -> Result<i16, Error<SPI>> {
+        let mut buffer = [0u8; 2];
+        self.spi.read(&mut buffer).map_err(Error::SpiError)?;
+        Ok(read_thermocouple_raw(buffer)?)
+    }
+
+    /// Reads the thermocouple temperature and converts it into degrees in the provided unit. Checks if there is a fault but doesn't detect what kind of fault it is
+    pub fn read_thermocouple(&mut self, unit: Unit) -> Result<f32, Error<SPI>> {
+        let data = self.read_thermocouple_raw()?;
+        Ok(read_thermocouple(data, unit))
+    }
+
+    /// Reads both the thermocouple and the internal temperatures, leaving them as raw ADC counts and resolves faults to one of vcc short, ground short or missing thermocouple
+    pub fn read_all_raw(&mut self) -> Result<FullResultRaw, Error<SPI>> {
+        let mut buffer = [0u8; 4];
+        self.spi.read(&mut buffer).map_err(Error::SpiError)?;
+        Ok(read_all_raw(buffer)?)
+    }
+
+    /// Reads both the thermocouple and the internal temperatures, converts them into degrees in the provided unit and resolves faults to one of vcc short, ground short or missing thermocouple
+    pub fn read_all(&mut self, unit: Unit) -> Result<FullResult, Error<SPI>> {
+        let raw = self.read_all_raw()?;
+        Ok(read_all(raw, unit))
+    }
+
+    /// Reads both the thermocouple and the internal temperatures and reports the fault bits instead of aborting on the first one set
+    pub fn read_all_with_faults(&mut self, unit: Unit) -> Result<FullResultWithFaults, Error<SPI>> {
+        let mut buffer = [0u8; 4];
+        self.spi.read(&mut buffer).map_err(Error::SpiError)?;
+        let raw = read_all_with_faults_raw(buffer);
+        Ok(read_all_with_faults(raw, unit))
+    }
+
+    /// Reads `samples` times, averaging the raw ADC counts and uniting the fault bits.
+    ///
+    /// The MAX31855 only finishes a fresh conversion every ~100 ms, so `min_interval_ns`
+    /// is waited (via `delay`) between consecutive reads to avoid sampling the same
+    /// stale conversion twice. Averaging is done on the raw counts before the single
+    /// final unit conversion, and any fault seen mid-burst is surfaced on the result's
+    /// [`FaultStatus`](crate::FaultStatus) rather than silently folded into the mean.
+    pub fn read_all_averaged<D: DelayNs>(
+        &mut self,
+        samples: NonZeroU16,
+        min_interval_ns: u32,
+        delay: &mut D,
+        unit: Unit,
+    ) -> Result<FullResultWithFaults, Error<SPI>> {
+        let mut averager = Averager::new();
+
+        for i in 0..samples.get() {
+            if i != 0 {
+                delay.delay_ns(min_interval_ns);
+            }
+            let mut buffer = [0u8; 4];
+            self.spi.read(&mut buffer).map_err(Error::SpiError)?;
+            averager.push(read_all_with_faults_raw(buffer));
+        }
+
+        // `samples` is non-zero, so the averager always has at least one reading.
+        let raw = averager.finish().expect("at least one sample accumulated");
+        Ok(read_all_with_faults(raw, unit))
+    }
+}
+
+/// The `embedded-hal-async` counterpart of [`Max31855`], built on
+/// [`embedded_hal_async::spi::SpiDevice`].
+#[cfg(feature = "async")]
+pub mod asynch {
+    use super::*;
+    use embedded_hal_async::delay::DelayNs;
+    use embedded_hal_async::spi::SpiDevice;
+
+    /// An async MAX31855 driver that owns an [`embedded_hal_async::spi::SpiDevice`].
+    pub struct Max31855<SPI> {
+        spi: SPI,
+    }
+
+    impl<SPI> Max31855<SPI>
+    where
+        SPI: SpiDevice,
+    {
+        /// Create a driver that owns `spi`
+        pub fn new(spi: SPI) -> Self {
+            Self { spi }
+        }
+
+        /// Release the underlying [`SpiDevice`]
+        pub fn release(self) -> SPI {
+            self.spi
+        }
+
+        /// Reads the thermocouple temperature and leaves it as a raw ADC count. Checks if there is a fault but doesn't detect what kind of fault it is
+        pub async fn read_thermocouple_raw(&mut self) -> Result<i16, Error<SPI>> {
+            let mut buffer = [0u8; 2];
+            self.spi.read(&mut buffer).await.map_err(Error::SpiError)?;
+            Ok(read_thermocouple_raw(buffer)?)
+        }
+
+        /// Reads the thermocouple temperature and converts it into degrees in the provided unit. Checks if there is a fault but doesn't detect what kind of fault it is
+        pub async fn read_thermocouple(&mut self, unit: Unit) -> Result<f32, Error<SPI>> {
+            let data = self.read_thermocouple_raw().await?;
+            Ok(read_thermocouple(data, unit))
+        }
+
+        /// Reads both the thermocouple and the internal temperatures, leaving them as raw ADC counts and resolves faults to one of vcc short, ground short or missing thermocouple
+        pub async fn read_all_raw(&mut self) -> Result<FullResultRaw, Error<SPI>> {
+            let mut buffer = [0u8; 4];
+            self.spi.read(&mut buffer).await.map_err(Error::SpiError)?;
+            Ok(read_all_raw(buffer)?)
+        }
+
+        /// Reads both the thermocouple and the internal temperatures, converts them into degrees in the provided unit and resolves faults to one of vcc short, ground short or missing thermocouple
+        pub async fn read_all(&mut self, unit: Unit) -> Result<FullResult, Error<SPI>> {
+            let raw = self.read_all_raw().await?;
+            Ok(read_all(raw, unit))
+        }
+
+        /// Reads both the thermocouple and the internal temperatures and reports the fault bits instead of aborting on the first one set
+        pub async fn read_all_with_faults(
+            &mut self,
+            unit: Unit,
+        ) -> Result<FullResultWithFaults, Error<SPI>> {
+            let mut buffer = [0u8; 4];
+            self.spi.read(&mut buffer).await.map_err(Error::SpiError)?;
+            let raw = read_all_with_faults_raw(buffer);
+            Ok(read_all_with_faults(raw, unit))
+        }
+
+        /// Reads `samples` times, averaging the raw ADC counts and uniting the fault bits.
+        ///
+        /// The MAX31855 only finishes a fresh conversion every ~100 ms, so `min_interval_ns`
+        /// is awaited (via `delay`) between consecutive reads to avoid sampling the same
+        /// stale conversion twice. Averaging is done on the raw counts before the single
+        /// final unit conversion, and any fault seen mid-burst is surfaced on the result's
+        /// [`FaultStatus`](crate::FaultStatus) rather than silently folded into the mean.
+        pub async fn read_all_averaged<D: DelayNs>(
+            &mut self,
+            samples: NonZeroU16,
+            min_interval_ns: u32,
+            delay: &mut D,
+            unit: Unit,
+        ) -> Result<FullResultWithFaults, Error<SPI>> {
+            let mut averager = Averager::new();
+
+            for i in 0..samples.get() {
+                if i != 0 {
+                    delay.delay_ns(min_interval_ns).await;
+                }
+                let mut buffer = [0u8; 4];
+                self.spi.read(&mut buffer).await.map_err(Error::SpiError)?;
+                averager.push(read_all_with_faults_raw(buffer));
+            }
+
+            // `samples` is non-zero, so the averager always has at least one reading.
+            let raw = averager.finish().expect("at least one sample accumulated");
+            Ok(read_all_with_faults(raw, unit))
+        }
+    }
+}